@@ -37,6 +37,7 @@ async fn main() -> std::io::Result<()> {
 ```
 */
 
+use std::borrow::Cow;
 use std::rc::Rc;
 use std::task::{Context, Poll};
 
@@ -52,13 +53,19 @@ pub(crate) use actix_web_v2 as actix_web;
 #[cfg(feature = "v3")]
 pub(crate) use actix_web_v3 as actix_web;
 
-use actix_web::cookie::{Cookie, CookieJar};
+#[cfg(feature = "session-store")]
+use actix_session::UserSession;
+
+use actix_web::cookie::time::Duration;
+use actix_web::cookie::{Cookie, CookieJar, Key};
 use actix_web::dev::{MessageBody, ServiceRequest, ServiceResponse};
 use actix_web::error::{Error, ErrorBadRequest, Result};
 use actix_web::{FromRequest, HttpMessage, HttpRequest, HttpResponse, Responder};
 
-#[derive(Debug)]
-struct FlashCookie(Cookie<'static>);
+pub use actix_web::cookie::SameSite;
+
+#[derive(Clone)]
+struct FlashPayload(String);
 #[derive(Clone)]
 struct FlashCookieValue(String);
 
@@ -78,6 +85,10 @@ struct ValuedMessageRef<'a, T> {
     value: &'a T
 }
 
+fn read_flash_value(req: &HttpRequest) -> Option<String> {
+    req.extensions().get::<FlashPayload>().map(|FlashPayload(value)| value.clone())
+}
+
 impl<T> FromRequest for Message<T>
 where
     T: DeserializeOwned + Serialize,
@@ -87,10 +98,9 @@ where
     type Error = Error;
 
     fn from_request(req: &HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
-        if let Some(cookie) = req.extensions().get::<FlashCookie>() {
-            match serde_json::from_str(cookie.0.value()) {
-                Ok(ValuedMessage { value }) => { return ok(Message(value)); },
-                _ => {}
+        if let Some(value) = read_flash_value(req) {
+            if let Ok(ValuedMessage { value }) = serde_json::from_str(&value) {
+                return ok(Message(value));
             }
         }
         err(ErrorBadRequest("Invalid/missing flash cookie"))
@@ -162,51 +172,419 @@ where
     }
 }
 
+/// The severity of a [`FlashMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Level {
+    Debug,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A single, leveled flash message, for use with [`IncomingFlashMessages`] and
+/// `Response<R, Vec<FlashMessage>>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlashMessage {
+    pub level: Level,
+    pub content: String,
+}
+
+impl<R> Response<R, Vec<FlashMessage>>
+where
+    R: Responder,
+{
+    fn push(mut self, level: Level, content: impl Into<String>) -> Self {
+        let message = self.message.get_or_insert_with(|| Message(Vec::new()));
+        message.0.push(FlashMessage { level, content: content.into() });
+        self
+    }
+
+    /// Queue a `Level::Debug` message.
+    pub fn debug(self, content: impl Into<String>) -> Self {
+        self.push(Level::Debug, content)
+    }
+
+    /// Queue a `Level::Info` message.
+    pub fn info(self, content: impl Into<String>) -> Self {
+        self.push(Level::Info, content)
+    }
+
+    /// Queue a `Level::Success` message.
+    pub fn success(self, content: impl Into<String>) -> Self {
+        self.push(Level::Success, content)
+    }
+
+    /// Queue a `Level::Warning` message.
+    pub fn warning(self, content: impl Into<String>) -> Self {
+        self.push(Level::Warning, content)
+    }
+
+    /// Queue a `Level::Error` message.
+    pub fn error(self, content: impl Into<String>) -> Self {
+        self.push(Level::Error, content)
+    }
+}
+
+/// Extractor for all pending, [`Level`]-tagged flash messages.
+///
+/// Unlike [`Message<T>`], this never fails: when there is no (or no valid) flash cookie, it
+/// simply yields an empty set of messages.
+#[derive(Debug, Default)]
+pub struct IncomingFlashMessages(Vec<FlashMessage>);
+
+impl IncomingFlashMessages {
+    /// Iterate over the pending flash messages, in the order they were queued.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &FlashMessage> {
+        self.0.iter()
+    }
+}
+
+impl FromRequest for IncomingFlashMessages {
+    type Config = ();
+    type Future = Ready<Result<Self, Self::Error>>;
+    type Error = Error;
+
+    fn from_request(req: &HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        let messages = read_flash_value(req)
+            .and_then(|value| serde_json::from_str::<ValuedMessage<Vec<FlashMessage>>>(&value).ok())
+            .map(|ValuedMessage { value }| value)
+            .unwrap_or_default();
+
+        ok(IncomingFlashMessages(messages))
+    }
+}
+
+/// A pluggable storage backend for the flash payload.
+///
+/// [`CookieStore`] (the default) round-trips the payload through the flash cookie itself, same
+/// as this crate has always done. Implement this trait for a different transport - see
+/// [`SessionStore`] - when the payload may be too large for a cookie.
+pub trait FlashMessageStore {
+    /// Pull the pending flash payload (the raw JSON envelope) out of the incoming request, if
+    /// any, stored under `name`.
+    fn load(&self, req: &ServiceRequest, name: &str) -> Option<String>;
+
+    /// Persist the flash payload for the next request under `name`, or clear it if `payload` is
+    /// `None`.
+    fn store<B: MessageBody>(
+        &self,
+        res: &mut ServiceResponse<B>,
+        name: &str,
+        payload: Option<String>,
+    ) -> Result<()>;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Protection {
+    Signed,
+    Private,
+}
+
+/// The default [`FlashMessageStore`]: reads and writes the flash payload through the flash
+/// cookie itself, optionally signed or encrypted with a [`Key`].
+#[derive(Clone)]
+pub struct CookieStore {
+    key: Option<Rc<Key>>,
+    protection: Protection,
+    path: Cow<'static, str>,
+    domain: Option<Cow<'static, str>>,
+    secure: bool,
+    http_only: bool,
+    same_site: SameSite,
+    max_age: Option<Duration>,
+}
+
+impl Default for CookieStore {
+    fn default() -> Self {
+        Self {
+            key: None,
+            protection: Protection::Signed,
+            path: Cow::Borrowed("/"),
+            domain: None,
+            secure: false,
+            http_only: true,
+            same_site: SameSite::Lax,
+            max_age: None,
+        }
+    }
+}
+
+impl CookieStore {
+    /// Sign the flash cookie using the given `Key`, so that tampering or forgery can be
+    /// detected. The cookie's value is still plaintext and readable by the client - use
+    /// [`CookieStore::with_private_key`] if it must also stay confidential. When no key is set,
+    /// the flash cookie is stored as plain JSON with no integrity check at all.
+    pub fn with_key(mut self, key: Key) -> Self {
+        self.key = Some(Rc::new(key));
+        self.protection = Protection::Signed;
+        self
+    }
+
+    /// Encrypt (and authenticate) the flash cookie using the given `Key`, so its value can be
+    /// neither read nor forged by the client.
+    pub fn with_private_key(mut self, key: Key) -> Self {
+        self.key = Some(Rc::new(key));
+        self.protection = Protection::Private;
+        self
+    }
+
+    /// Set the `Path` attribute of the flash cookie. Defaults to `"/"`.
+    pub fn path(mut self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Set the `Domain` attribute of the flash cookie.
+    pub fn domain(mut self, domain: impl Into<Cow<'static, str>>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Set the `Secure` attribute of the flash cookie. Defaults to `false`.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Set the `HttpOnly` attribute of the flash cookie. Defaults to `true`.
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Set the `SameSite` attribute of the flash cookie. Defaults to `SameSite::Lax`.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = same_site;
+        self
+    }
+
+    /// Set the `Max-Age` attribute of the flash cookie.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    fn apply_attrs(&self, cookie: &mut Cookie<'static>) {
+        cookie.set_path(self.path.clone());
+        cookie.set_secure(self.secure);
+        cookie.set_http_only(self.http_only);
+        cookie.set_same_site(self.same_site);
+
+        if let Some(domain) = &self.domain {
+            cookie.set_domain(domain.clone());
+        }
+
+        if let Some(max_age) = self.max_age {
+            cookie.set_max_age(max_age);
+        }
+    }
+
+    fn removal_cookie(&self, name: &str) -> Cookie<'static> {
+        let mut cookie = Cookie::build(name.to_owned(), "").path(self.path.clone()).finish();
+        if let Some(domain) = &self.domain {
+            cookie.set_domain(domain.clone());
+        }
+        cookie
+    }
+}
+
+impl FlashMessageStore for CookieStore {
+    fn load(&self, req: &ServiceRequest, name: &str) -> Option<String> {
+        let cookie = req.cookie(name)?;
+
+        match &self.key {
+            Some(key) => {
+                let mut jar = CookieJar::new();
+                jar.add_original(cookie);
+                match self.protection {
+                    Protection::Signed => jar.signed(key).get(name).map(|c| c.value().to_owned()),
+                    Protection::Private => jar.private(key).get(name).map(|c| c.value().to_owned()),
+                }
+            }
+            None => Some(cookie.value().to_owned()),
+        }
+    }
+
+    fn store<B: MessageBody>(
+        &self,
+        res: &mut ServiceResponse<B>,
+        name: &str,
+        payload: Option<String>,
+    ) -> Result<()> {
+        if let Some(json) = payload {
+            let mut cookie = Cookie::new(name.to_owned(), json);
+            self.apply_attrs(&mut cookie);
+
+            match &self.key {
+                Some(key) => {
+                    let mut jar = CookieJar::new();
+                    match self.protection {
+                        Protection::Signed => { jar.signed_mut(key).add(cookie); },
+                        Protection::Private => { jar.private_mut(key).add(cookie); },
+                    }
+                    for cookie in jar.delta() {
+                        res.response_mut().add_cookie(cookie)?;
+                    }
+                }
+                None => { res.response_mut().add_cookie(&cookie)?; },
+            }
+
+            return Ok(());
+        }
+
+        let mut jar = CookieJar::new();
+        if let Some(cookie) = res.request().cookie(name) {
+            jar.add_original(cookie);
+            jar.remove(self.removal_cookie(name));
+        }
+
+        for cookie in jar.delta() {
+            res.response_mut().add_cookie(cookie)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`FlashMessageStore`] that persists the flash payload in the `actix-session` `Session`
+/// instead of a dedicated cookie, so the payload isn't capped by cookie size. Only the session
+/// id travels in a cookie; `actix-session` owns that cookie's attributes. Requires the
+/// `session-store` feature.
+#[cfg(feature = "session-store")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SessionStore;
+
+#[cfg(feature = "session-store")]
+impl FlashMessageStore for SessionStore {
+    fn load(&self, req: &ServiceRequest, name: &str) -> Option<String> {
+        let session = req.get_session();
+        let payload = session.get::<String>(name).ok().flatten();
+        if payload.is_some() {
+            session.remove(name);
+        }
+        payload
+    }
+
+    fn store<B: MessageBody>(
+        &self,
+        res: &mut ServiceResponse<B>,
+        name: &str,
+        payload: Option<String>,
+    ) -> Result<()> {
+        if let Some(json) = payload {
+            res.request().get_session().set(name, json)?;
+        }
+        Ok(())
+    }
+}
+
 /// The flash middleware transformer
-pub struct Flash {
+pub struct Flash<Store = CookieStore> {
     cookie_name: Rc<str>,
+    store: Rc<Store>,
 }
 
-impl Flash {
+impl Flash<CookieStore> {
     /// Create a new flash middleware transformer, using the given string as the cookie name.
     pub fn new<I: Into<Rc<str>>>(cookie_name: I) -> Self {
-        Self { cookie_name: cookie_name.into() }
+        Self { cookie_name: cookie_name.into(), store: Rc::new(CookieStore::default()) }
+    }
+
+    /// Sign the flash cookie using the given `Key`, so that tampering or forgery can be
+    /// detected. The cookie's value is still plaintext and readable by the client - use
+    /// [`Flash::with_private_key`] if it must also stay confidential. When no key is set, the
+    /// flash cookie is stored as plain JSON with no integrity check at all.
+    pub fn with_key(self, key: Key) -> Self {
+        self.map_store(|store| store.with_key(key))
+    }
+
+    /// Encrypt (and authenticate) the flash cookie using the given `Key`, so its value can be
+    /// neither read nor forged by the client.
+    pub fn with_private_key(self, key: Key) -> Self {
+        self.map_store(|store| store.with_private_key(key))
+    }
+
+    /// Set the `Path` attribute of the flash cookie. Defaults to `"/"`.
+    pub fn path(self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.map_store(|store| store.path(path))
+    }
+
+    /// Set the `Domain` attribute of the flash cookie.
+    pub fn domain(self, domain: impl Into<Cow<'static, str>>) -> Self {
+        self.map_store(|store| store.domain(domain))
+    }
+
+    /// Set the `Secure` attribute of the flash cookie. Defaults to `false`.
+    pub fn secure(self, secure: bool) -> Self {
+        self.map_store(|store| store.secure(secure))
+    }
+
+    /// Set the `HttpOnly` attribute of the flash cookie. Defaults to `true`.
+    pub fn http_only(self, http_only: bool) -> Self {
+        self.map_store(|store| store.http_only(http_only))
+    }
+
+    /// Set the `SameSite` attribute of the flash cookie. Defaults to `SameSite::Lax`.
+    pub fn same_site(self, same_site: SameSite) -> Self {
+        self.map_store(|store| store.same_site(same_site))
+    }
+
+    /// Set the `Max-Age` attribute of the flash cookie.
+    pub fn max_age(self, max_age: Duration) -> Self {
+        self.map_store(|store| store.max_age(max_age))
+    }
+
+    fn map_store(self, f: impl FnOnce(CookieStore) -> CookieStore) -> Self {
+        let store = (*self.store).clone();
+        Self { store: Rc::new(f(store)), ..self }
     }
 }
 
-impl Default for Flash {
+impl<Store: FlashMessageStore> Flash<Store> {
+    /// Use a different [`FlashMessageStore`] backend, e.g. [`SessionStore`] for payloads too
+    /// large to fit in a cookie.
+    pub fn with_store<NewStore: FlashMessageStore>(self, store: NewStore) -> Flash<NewStore> {
+        Flash { cookie_name: self.cookie_name, store: Rc::new(store) }
+    }
+}
+
+impl Default for Flash<CookieStore> {
     fn default() -> Self {
         Self::new("_flash")
     }
 }
 
 /// The actual flash middleware
-pub struct FlashMiddleware<S> {
+pub struct FlashMiddleware<S, Store = CookieStore> {
     cookie_name: Rc<str>,
+    store: Rc<Store>,
     service: S,
 }
 
-impl<S, B> Transform<S> for Flash
+impl<S, B, Store> Transform<S> for Flash<Store>
 where
     S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
     B: MessageBody + 'static,
+    Store: FlashMessageStore + 'static,
 {
     type Request = ServiceRequest;
     type Response = ServiceResponse<B>;
     type Error = Error;
     type InitError = ();
-    type Transform = FlashMiddleware<S>;
+    type Transform = FlashMiddleware<S, Store>;
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
-        ok(FlashMiddleware { service, cookie_name: self.cookie_name.clone() })
+        ok(FlashMiddleware { service, cookie_name: self.cookie_name.clone(), store: self.store.clone() })
     }
 }
 
-impl<S, B> Service for FlashMiddleware<S>
+impl<S, B, Store> Service for FlashMiddleware<S, Store>
 where
     S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
     B: MessageBody + 'static,
+    Store: FlashMessageStore + 'static,
 {
     type Request = ServiceRequest;
     type Response = ServiceResponse<B>;
@@ -219,29 +597,17 @@ where
 
     fn call(&mut self, req: ServiceRequest) -> Self::Future {
         let cookie_name = String::from(self.cookie_name.as_ref());
+        let store = self.store.clone();
 
-        if let Some(cookie) = req.cookie(&cookie_name) {
-            req.extensions_mut().insert(FlashCookie(cookie));
+        if let Some(payload) = store.load(&req, &cookie_name) {
+            req.extensions_mut().insert(FlashPayload(payload));
         }
 
-        Box::pin(self.service.call(req).and_then(|mut res| async move {
-            let maybe_set_cookie = res.response().extensions().get::<FlashCookieValue>().cloned();
-
-            if let Some(FlashCookieValue(json)) = maybe_set_cookie {
-                let mut cookie = Cookie::new(cookie_name.clone(), json);
-                cookie.set_path("/");
-                res.response_mut().add_cookie(&cookie)?;
-            }
+        Box::pin(self.service.call(req).and_then(move |mut res| async move {
+            let payload = res.response().extensions().get::<FlashCookieValue>().cloned()
+                .map(|FlashCookieValue(json)| json);
 
-            let mut jar = CookieJar::new();
-            if let Some(cookie) = res.request().cookie(&cookie_name) {
-                jar.add_original(cookie);
-                jar.remove(Cookie::build(cookie_name, "").path("/").finish());
-            }
-
-            for cookie in jar.delta() {
-                res.response_mut().add_cookie(cookie)?;
-            }
+            store.store(&mut res, &cookie_name, payload)?;
 
             Ok(res)
         }))